@@ -0,0 +1,44 @@
+use near_sdk::serde_json::json;
+use near_sdk::{env, AccountId};
+
+/// NEP-297 standard name and version for events emitted by this contract.
+/// https://github.com/near/NEPs/blob/master/neps/nep-0297.md
+const STANDARD_NAME: &str = "token_list";
+const STANDARD_VERSION: &str = "1.0.0";
+
+/// Token list mutation events. Emitted as a single `EVENT_JSON:` log line so
+/// off-chain indexers can subscribe to list changes deterministically
+/// instead of scraping free-text logs.
+pub enum TokenListEvent<'a> {
+    TokenAdded { token: &'a AccountId },
+    TokenRemoved { token: &'a AccountId },
+    TokensAdded { tokens: &'a [AccountId], count: u64 },
+    Paused,
+    Unpaused,
+}
+
+impl TokenListEvent<'_> {
+    pub fn emit(&self) {
+        let (event, data) = match self {
+            TokenListEvent::TokenAdded { token } => ("token_added", json!([{ "token": token }])),
+            TokenListEvent::TokenRemoved { token } => {
+                ("token_removed", json!([{ "token": token }]))
+            }
+            TokenListEvent::TokensAdded { tokens, count } => (
+                "tokens_added",
+                json!([{ "tokens": tokens, "count": count }]),
+            ),
+            TokenListEvent::Paused => ("paused", json!([{}])),
+            TokenListEvent::Unpaused => ("unpaused", json!([{}])),
+        };
+        env::log_str(&format!(
+            "EVENT_JSON:{}",
+            json!({
+                "standard": STANDARD_NAME,
+                "version": STANDARD_VERSION,
+                "event": event,
+                "data": data,
+            })
+        ));
+    }
+}
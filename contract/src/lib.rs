@@ -1,12 +1,33 @@
+mod events;
+mod migration;
+
+use events::TokenListEvent;
+use migration::OldTokenList;
 use near_contract_standards::fungible_token::core::ext_ft_core;
 use near_contract_standards::fungible_token::metadata::FungibleTokenMetadata;
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
 use near_sdk::json_types::U128;
-use near_sdk::store::UnorderedSet;
+use near_sdk::store::{LookupMap, UnorderedMap, UnorderedSet};
 use near_sdk::{
-    env, ext_contract, near_bindgen, require, AccountId, Promise, PromiseError, PromiseOrValue,
+    env, ext_contract, near_bindgen, require, AccountId, Balance, Gas, Promise, PromiseError,
+    PromiseOrValue,
 };
 
+/// Gas kept back for `upgrade` itself; the `migrate` call it triggers is
+/// given whatever gas is still available on the current call beyond this.
+const MIGRATE_CALL_GAS: Gas = Gas(5_000_000_000_000);
+
+/// Fixed allowance for the bytes a cached `FungibleTokenMetadata` occupies,
+/// used to size the deposit required up front; whatever the deposit holds
+/// beyond the real cost is refunded once the metadata is actually known.
+/// Metadata is not limited to this size, and storage beyond the attached
+/// deposit is not charged to the caller.
+const MAX_METADATA_STORAGE_BYTES: u64 = 1_000;
+
+/// Default static gas attached to the `ft_balance_of`/`ft_metadata`
+/// verification calls, tunable per-contract via `set_verification_gas`.
+const DEFAULT_VERIFICATION_GAS: Gas = Gas(10_000_000_000_000);
+
 #[ext_contract(ext_ft_metadata)]
 trait FungibleTokenMetadataContract {
     fn ft_metadata(&self) -> FungibleTokenMetadata;
@@ -15,74 +36,318 @@ trait FungibleTokenMetadataContract {
 #[near_bindgen]
 #[derive(BorshDeserialize, BorshSerialize)]
 pub struct TokenList {
-    tokens: UnorderedSet<AccountId>,
+    owner: AccountId,
+    pending_owner: Option<AccountId>,
+    curators: UnorderedSet<AccountId>,
+    tokens: UnorderedMap<AccountId, FungibleTokenMetadata>,
+    paused: bool,
+    /// Account that staked the storage for each listed token, so its
+    /// deposit can be refunded when that token is removed.
+    token_depositor: LookupMap<AccountId, AccountId>,
+    /// NEAR currently staked per account to cover the storage of the
+    /// tokens it has added. NEP-145-style accounting so listing stays
+    /// economically bounded instead of bloating state for free.
+    storage_balance: LookupMap<AccountId, Balance>,
+    /// Static gas attached to the `ft_balance_of`/`ft_metadata` calls made
+    /// while verifying a new token, tunable so the registry isn't locked
+    /// to the SDK's implicit gas split for tokens with heavier views.
+    verification_gas: Gas,
 }
 
-impl Default for TokenList {
-    fn default() -> Self {
+#[near_bindgen]
+impl TokenList {
+    #[init]
+    pub fn new(owner: AccountId) -> Self {
         Self {
-            tokens: UnorderedSet::new(b"t".to_vec()),
+            owner,
+            pending_owner: None,
+            curators: UnorderedSet::new(b"c".to_vec()),
+            tokens: UnorderedMap::new(b"t".to_vec()),
+            paused: false,
+            token_depositor: LookupMap::new(b"d".to_vec()),
+            storage_balance: LookupMap::new(b"s".to_vec()),
+            verification_gas: DEFAULT_VERIFICATION_GAS,
         }
     }
-}
 
-#[near_bindgen]
-impl TokenList {
+    /// Halts new additions during an incident, without redeploying the
+    /// contract. `get_tokens` stays readable while paused. Owner-only.
+    pub fn pause(&mut self) {
+        self.require_owner();
+        self.paused = true;
+        TokenListEvent::Paused.emit();
+    }
+
+    /// Resumes additions after a pause. Owner-only.
+    pub fn unpause(&mut self) {
+        self.require_owner();
+        self.paused = false;
+        TokenListEvent::Unpaused.emit();
+    }
+
+    /// Grants curator privileges to `curator`. Owner-only.
+    pub fn add_curator(&mut self, curator: AccountId) {
+        self.require_owner();
+        self.curators.insert(curator);
+    }
+
+    /// Revokes curator privileges from `curator`. Owner-only.
+    pub fn remove_curator(&mut self, curator: AccountId) {
+        self.require_owner();
+        self.curators.remove(&curator);
+    }
+
+    /// Sets the static gas attached to the cross-contract verification
+    /// calls in `verify_account_is_token`, for tokens whose view methods
+    /// are unexpectedly heavy or that proxy through another contract.
+    /// Owner-only.
+    pub fn set_verification_gas(&mut self, gas: Gas) {
+        self.require_owner();
+        self.verification_gas = gas;
+    }
+
+    /// Starts a two-step ownership transfer. The new owner must call
+    /// `accept_ownership` before the transfer takes effect, so a typo'd
+    /// `new_owner` can't permanently lock the registry. Owner-only.
+    pub fn propose_new_owner(&mut self, new_owner: AccountId) {
+        self.require_owner();
+        self.pending_owner = Some(new_owner);
+    }
+
+    /// Completes a pending ownership transfer. Callable only by the
+    /// account proposed in `propose_new_owner`.
+    pub fn accept_ownership(&mut self) {
+        let caller = env::predecessor_account_id();
+        require!(
+            self.pending_owner.as_ref() == Some(&caller),
+            "Caller is not the pending owner"
+        );
+        self.owner = caller;
+        self.pending_owner = None;
+    }
+
+    /// Redeploys the contract to the wasm passed as raw call input, then
+    /// calls `migrate` to reshape existing state into the new layout.
+    /// Owner-only, since a bad deploy would brick the registry.
+    pub fn upgrade(&mut self) -> Promise {
+        self.require_owner();
+        let code = env::input().expect("Expected the new contract wasm as input");
+        let attached_gas = env::prepaid_gas() - env::used_gas() - MIGRATE_CALL_GAS;
+
+        Promise::new(env::current_account_id())
+            .deploy_contract(code)
+            .function_call("migrate".to_string(), Vec::new(), 0, attached_gas)
+    }
+
+    /// Adds `token`, staking attached NEAR to cover its storage. Any
+    /// excess above the actual cost is refunded once the real metadata
+    /// size is known.
+    #[payable]
     pub fn add_token(&mut self, token: AccountId) -> PromiseOrValue<bool> {
-        let token_promise = self.get_add_token_to_list_promise(token);
+        self.require_curator();
+        self.require_not_paused();
+        let payer = env::predecessor_account_id();
+        let deposit = env::attached_deposit();
+        require!(
+            deposit >= Self::storage_cost(Self::estimated_entry_storage_bytes(&token)),
+            "Attached deposit does not cover storage staking cost"
+        );
+
+        let token_promise = self.get_add_token_to_list_promise(token.clone(), payer.clone());
         if let Some(token_promise) = token_promise {
-            PromiseOrValue::Promise(token_promise)
+            PromiseOrValue::Promise(token_promise.then(
+                Self::ext(env::current_account_id()).add_token_storage_refund_callback(
+                    token,
+                    payer,
+                    U128(deposit),
+                ),
+            ))
         } else {
+            if deposit > 0 {
+                Promise::new(payer).transfer(deposit);
+            }
             PromiseOrValue::Value(false)
         }
     }
 
     // TODO: Figure out mut tokens warning
+    /// Adds `tokens`, staking attached NEAR to cover all of their storage.
+    /// Any excess above the actual cost is refunded once added.
+    #[payable]
     pub fn add_tokens(&mut self, mut tokens: Vec<AccountId>) -> PromiseOrValue<u64> {
+        self.require_curator();
+        self.require_not_paused();
         tokens.sort_unstable();
         tokens.dedup();
-        let num_of_tokens = tokens.len();
-        require!(num_of_tokens.gt(&0), "No tokens provided");
+        require!(!tokens.is_empty(), "No tokens provided");
 
-        let promises = tokens
+        let payer = env::predecessor_account_id();
+        let deposit = env::attached_deposit();
+        let new_tokens: Vec<AccountId> = tokens
             .into_iter()
-            .filter_map(|token| self.get_add_token_to_list_promise(token))
+            .filter(|token| !self.tokens.contains_key(token))
+            .collect();
+        let required_bytes: u64 = new_tokens
+            .iter()
+            .map(Self::estimated_entry_storage_bytes)
+            .sum();
+        require!(
+            deposit >= Self::storage_cost(required_bytes),
+            "Attached deposit does not cover storage staking cost"
+        );
+
+        let promises = new_tokens
+            .iter()
+            .cloned()
+            .map(|token| self.add_token_to_list(token, payer.clone()))
             .reduce(|accum, p| accum.and(p));
         if let Some(promises) = promises {
-            PromiseOrValue::Promise(
-                promises.then(Self::ext(env::current_account_id()).add_tokens_callback()),
-            )
+            PromiseOrValue::Promise(promises.then(
+                Self::ext(env::current_account_id()).add_tokens_callback(
+                    new_tokens,
+                    payer,
+                    U128(deposit),
+                ),
+            ))
         } else {
+            if deposit > 0 {
+                Promise::new(payer).transfer(deposit);
+            }
             PromiseOrValue::Value(0)
         }
     }
 
+    /// Removes `token` from the list and refunds whichever account staked
+    /// its storage. Curator-only.
+    pub fn remove_token(&mut self, token: AccountId) -> bool {
+        self.require_curator();
+        let metadata = match self.tokens.remove(&token) {
+            Some(metadata) => metadata,
+            None => return false,
+        };
+        TokenListEvent::TokenRemoved { token: &token }.emit();
+
+        if let Some(depositor) = self.token_depositor.remove(&token) {
+            let freed_cost =
+                Self::storage_cost(Self::actual_entry_storage_bytes(&token, &metadata));
+            let remaining = self
+                .storage_balance
+                .get(&depositor)
+                .copied()
+                .unwrap_or(0)
+                .saturating_sub(freed_cost);
+            self.storage_balance.insert(depositor.clone(), remaining);
+            Promise::new(depositor).transfer(freed_cost);
+        }
+        true
+    }
+
+    /// NEAR currently staked by `account` to cover the storage of the
+    /// tokens it has added.
+    pub fn get_storage_balance(&self, account: AccountId) -> U128 {
+        U128(self.storage_balance.get(&account).copied().unwrap_or(0))
+    }
+
     pub fn get_tokens(&self, from_index: u64, limit: u64) -> Vec<&AccountId> {
-        let keys: Vec<&AccountId> = self.tokens.iter().collect();
+        let keys: Vec<&AccountId> = self.tokens.keys().collect();
         (from_index..std::cmp::min(from_index + limit, self.tokens.len().into()))
             .map(|index| *keys.get(index as usize).unwrap())
             .collect()
     }
 
-    fn get_add_token_to_list_promise(&self, token: AccountId) -> Option<Promise> {
-        if !self.tokens.contains(&token) {
-            Some(self.add_token_to_list(token))
+    /// Returns listed tokens together with their cached `ft_metadata`, so
+    /// frontends get everything in one view call instead of N extra RPCs.
+    pub fn get_tokens_with_metadata(
+        &self,
+        from_index: u64,
+        limit: u64,
+    ) -> Vec<(&AccountId, &FungibleTokenMetadata)> {
+        let entries: Vec<(&AccountId, &FungibleTokenMetadata)> = self.tokens.iter().collect();
+        (from_index..std::cmp::min(from_index + limit, self.tokens.len().into()))
+            .map(|index| *entries.get(index as usize).unwrap())
+            .collect()
+    }
+
+    /// Re-queries `ft_metadata` for an already-listed token and refreshes
+    /// the cached copy, for tokens that later change their display data.
+    pub fn refresh_metadata(&self, token: AccountId) -> Promise {
+        require!(self.tokens.contains_key(&token), "Token is not listed");
+        ext_ft_metadata::ext(token.clone())
+            .ft_metadata()
+            .then(Self::ext(env::current_account_id()).refresh_metadata_callback(token))
+    }
+
+    #[private]
+    pub fn refresh_metadata_callback(
+        &mut self,
+        #[callback_result] metadata: Result<FungibleTokenMetadata, PromiseError>,
+        token: AccountId,
+    ) -> FungibleTokenMetadata {
+        let metadata = metadata.expect("Unable to refresh token metadata");
+        metadata.assert_valid();
+        self.tokens.insert(token, metadata.clone());
+        metadata
+    }
+
+    fn require_owner(&self) {
+        require!(
+            env::predecessor_account_id() == self.owner,
+            "Caller is not the owner"
+        );
+    }
+
+    fn require_curator(&self) {
+        let caller = env::predecessor_account_id();
+        require!(
+            caller == self.owner || self.curators.contains(&caller),
+            "Caller is not a curator"
+        );
+    }
+
+    fn require_not_paused(&self) {
+        require!(!self.paused, "Token list is paused");
+    }
+
+    /// Estimate of the bytes a token's entry will occupy, used to size the
+    /// deposit required before its real metadata is known.
+    fn estimated_entry_storage_bytes(token: &AccountId) -> u64 {
+        token.as_str().len() as u64 + MAX_METADATA_STORAGE_BYTES
+    }
+
+    /// Exact bytes a token's entry occupies once its metadata is known.
+    fn actual_entry_storage_bytes(token: &AccountId, metadata: &FungibleTokenMetadata) -> u64 {
+        (token.try_to_vec().unwrap().len() + metadata.try_to_vec().unwrap().len()) as u64
+    }
+
+    fn storage_cost(bytes: u64) -> Balance {
+        Balance::from(bytes) * env::storage_byte_cost()
+    }
+
+    fn get_add_token_to_list_promise(&self, token: AccountId, payer: AccountId) -> Option<Promise> {
+        if !self.tokens.contains_key(&token) {
+            Some(self.add_token_to_list(token, payer))
         } else {
             None
         }
     }
 
-    fn add_token_to_list(&self, token: AccountId) -> Promise {
-        self.verify_account_is_token(&token)
-            .then(Self::ext(env::current_account_id()).add_token_to_list_callback(token))
+    fn add_token_to_list(&self, token: AccountId, payer: AccountId) -> Promise {
+        self.verify_account_is_token(&token).then(
+            Self::ext(env::current_account_id()).add_token_to_list_callback(token, payer),
+        )
     }
 
     fn verify_account_is_token(&self, token: &AccountId) -> Promise {
         env::log_str(&format!("Adding token '{}' to token list", token));
         let account_id: AccountId = env::signer_account_id();
         ext_ft_core::ext(token.clone())
+            .with_static_gas(self.verification_gas)
             .ft_balance_of(account_id)
-            .and(ext_ft_metadata::ext(token.clone()).ft_metadata())
+            .and(
+                ext_ft_metadata::ext(token.clone())
+                    .with_static_gas(self.verification_gas)
+                    .ft_metadata(),
+            )
             .then(Self::ext(env::current_account_id()).verify_account_is_token_callback())
     }
 
@@ -90,34 +355,100 @@ impl TokenList {
     pub fn verify_account_is_token_callback(
         #[callback_result] balance: Result<U128, PromiseError>,
         #[callback_result] metadata: Result<FungibleTokenMetadata, PromiseError>,
-    ) -> bool {
+    ) -> FungibleTokenMetadata {
+        let metadata =
+            metadata.expect("Provided token address does not have a ft_metadata method");
+        metadata.assert_valid();
+        require!(
+            balance
+                .expect("Provided token address does not have a ft_balance_of method")
+                .0
+                >= std::u128::MIN,
+            "Provided token address does not have a ft_balance_of method"
+        );
         metadata
-            .expect("Provided token address does not have a ft_metadata method")
-            .assert_valid();
-        balance
-            .expect("Provided token address does not have a ft_metadata method")
-            .0
-            >= std::u128::MIN
     }
 
     #[private]
     pub fn add_token_to_list_callback(
         &mut self,
-        #[callback_result] is_token_account: Result<bool, PromiseError>,
+        #[callback_result] metadata: Result<FungibleTokenMetadata, PromiseError>,
         token: AccountId,
+        payer: AccountId,
     ) -> bool {
-        require!(
-            is_token_account.expect("Unable to get result of token account verification"),
-            format!("The account {} is not a valid token account", token)
-        );
-        self.tokens.insert(token);
+        let metadata = metadata.expect("Unable to get result of token account verification");
+        let cost = Self::storage_cost(Self::actual_entry_storage_bytes(&token, &metadata));
+        self.tokens.insert(token.clone(), metadata);
+        self.token_depositor.insert(token.clone(), payer.clone());
+        let staked = self.storage_balance.get(&payer).copied().unwrap_or(0);
+        self.storage_balance.insert(payer, staked + cost);
+        TokenListEvent::TokenAdded { token: &token }.emit();
         true
     }
 
+    /// Refunds whatever part of the deposit `add_token` charged beyond the
+    /// entry's actual storage cost, now that its metadata is known.
     #[private]
-    pub fn add_tokens_callback() -> u64 {
+    pub fn add_token_storage_refund_callback(
+        &self,
+        #[callback_result] added: Result<bool, PromiseError>,
+        token: AccountId,
+        payer: AccountId,
+        deposit: U128,
+    ) -> bool {
+        let added = added.unwrap_or(false);
+        let used_cost = if added {
+            let metadata = self.tokens.get(&token).expect("Token should be listed");
+            Self::storage_cost(Self::actual_entry_storage_bytes(&token, metadata))
+        } else {
+            0
+        };
+        let refund = deposit.0.saturating_sub(used_cost);
+        if refund > 0 {
+            Promise::new(payer).transfer(refund);
+        }
+        added
+    }
+
+    /// Reshapes state left by the previous contract version into the
+    /// current `TokenList` layout. Called by `upgrade` right after
+    /// `deploy_contract`; never callable by anyone else.
+    #[private]
+    #[init(ignore_state)]
+    pub fn migrate() -> Self {
+        let old_state: OldTokenList =
+            env::state_read().expect("Failed to read old contract state");
+        old_state.into()
+    }
+
+    /// Refunds whatever part of the deposit `add_tokens` charged beyond
+    /// the batch's actual storage cost, now that metadata is known.
+    #[private]
+    pub fn add_tokens_callback(&self, tokens: Vec<AccountId>, payer: AccountId, deposit: U128) -> u64 {
         let num_of_tokens = env::promise_results_count();
-        env::log_str(&format!("Saved {} tokens to list", num_of_tokens));
+        let added: Vec<AccountId> = tokens
+            .iter()
+            .filter(|token| self.tokens.contains_key(*token))
+            .cloned()
+            .collect();
+        TokenListEvent::TokensAdded {
+            tokens: &added,
+            count: added.len() as u64,
+        }
+        .emit();
+
+        let used_bytes: u64 = tokens
+            .iter()
+            .filter_map(|token| {
+                self.tokens
+                    .get(token)
+                    .map(|metadata| Self::actual_entry_storage_bytes(token, metadata))
+            })
+            .sum();
+        let refund = deposit.0.saturating_sub(Self::storage_cost(used_bytes));
+        if refund > 0 {
+            Promise::new(payer).transfer(refund);
+        }
         num_of_tokens
     }
 }
@@ -136,6 +467,7 @@ impl TokenList {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use near_contract_standards::fungible_token::metadata::FT_METADATA_SPEC;
     use near_primitives_core::config::ViewConfig;
     use near_sdk::test_utils::VMContextBuilder;
     use near_sdk::{testing_env, VMContext};
@@ -149,17 +481,40 @@ mod tests {
         }
     }
 
+    fn owner() -> AccountId {
+        "owner.testnet".parse().unwrap()
+    }
+
+    fn sample_metadata() -> FungibleTokenMetadata {
+        FungibleTokenMetadata {
+            spec: FT_METADATA_SPEC.to_string(),
+            name: "Sample Token".to_string(),
+            symbol: "SMPL".to_string(),
+            icon: None,
+            reference: None,
+            reference_hash: None,
+            decimals: 24,
+        }
+    }
+
+    // mock the context for testing calls that are gated on the predecessor account
+    fn get_context_as(predecessor: AccountId) -> VMContext {
+        VMContextBuilder::new()
+            .predecessor_account_id(predecessor)
+            .build()
+    }
+
     #[test]
     fn get_tokens() {
         let context = get_context(vec![], None);
         testing_env!(context);
-        let mut contract = TokenList::default();
+        let mut contract = TokenList::new(owner());
         let tokens: Vec<AccountId> = vec![
             "linear-protocol.testnet".parse().unwrap(),
             "wrap.testnet".parse().unwrap(),
         ];
         tokens.iter().for_each(|token| {
-            contract.tokens.insert(token.clone());
+            contract.tokens.insert(token.clone(), sample_metadata());
         });
         assert_eq!(
             vec![&tokens[0], &tokens[1]],
@@ -171,13 +526,13 @@ mod tests {
     fn get_tokens_subset() {
         let context = get_context(vec![], None);
         testing_env!(context);
-        let mut contract = TokenList::default();
+        let mut contract = TokenList::new(owner());
         let tokens: Vec<AccountId> = vec![
             "linear-protocol.testnet".parse().unwrap(),
             "wrap.testnet".parse().unwrap(),
         ];
         tokens.iter().for_each(|token| {
-            contract.tokens.insert(token.clone());
+            contract.tokens.insert(token.clone(), sample_metadata());
         });
         assert_eq!(vec![&tokens[0]], contract.get_tokens(0, 1));
     }
@@ -186,14 +541,147 @@ mod tests {
     fn get_tokens_out_of_bounds_index() {
         let context = get_context(vec![], None);
         testing_env!(context);
-        let mut contract = TokenList::default();
+        let mut contract = TokenList::new(owner());
         let tokens: Vec<AccountId> = vec![
             "linear-protocol.testnet".parse().unwrap(),
             "wrap.testnet".parse().unwrap(),
         ];
         tokens.iter().for_each(|token| {
-            contract.tokens.insert(token.clone());
+            contract.tokens.insert(token.clone(), sample_metadata());
         });
         assert_eq!(vec![] as Vec<&AccountId>, contract.get_tokens(1000, 1));
     }
+
+    #[test]
+    fn get_tokens_with_metadata_returns_cached_records() {
+        let context = get_context(vec![], None);
+        testing_env!(context);
+        let mut contract = TokenList::new(owner());
+        let token: AccountId = "wrap.testnet".parse().unwrap();
+        contract.tokens.insert(token.clone(), sample_metadata());
+
+        assert_eq!(
+            vec![(&token, &sample_metadata())],
+            contract.get_tokens_with_metadata(0, 10)
+        );
+    }
+
+    #[test]
+    fn add_curator_allows_owner() {
+        testing_env!(get_context_as(owner()));
+        let mut contract = TokenList::new(owner());
+        let curator: AccountId = "curator.testnet".parse().unwrap();
+        contract.add_curator(curator.clone());
+        assert!(contract.curators.contains(&curator));
+    }
+
+    #[test]
+    #[should_panic(expected = "Caller is not the owner")]
+    fn add_curator_rejects_non_owner() {
+        testing_env!(get_context_as("stranger.testnet".parse().unwrap()));
+        let mut contract = TokenList::new(owner());
+        contract.add_curator("curator.testnet".parse().unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "Caller is not a curator")]
+    fn remove_token_rejects_non_curator() {
+        testing_env!(get_context_as("stranger.testnet".parse().unwrap()));
+        let mut contract = TokenList::new(owner());
+        contract.remove_token("wrap.testnet".parse().unwrap());
+    }
+
+    #[test]
+    fn remove_token_allows_curator() {
+        testing_env!(get_context_as(owner()));
+        let mut contract = TokenList::new(owner());
+        let curator: AccountId = "curator.testnet".parse().unwrap();
+        contract.add_curator(curator.clone());
+        let token: AccountId = "wrap.testnet".parse().unwrap();
+        contract.tokens.insert(token.clone(), sample_metadata());
+
+        testing_env!(get_context_as(curator));
+        assert!(contract.remove_token(token));
+    }
+
+    #[test]
+    fn ownership_transfer_two_step_flow() {
+        testing_env!(get_context_as(owner()));
+        let mut contract = TokenList::new(owner());
+        let new_owner: AccountId = "new-owner.testnet".parse().unwrap();
+        contract.propose_new_owner(new_owner.clone());
+
+        testing_env!(get_context_as(new_owner.clone()));
+        contract.accept_ownership();
+        assert_eq!(contract.owner, new_owner);
+        assert_eq!(contract.pending_owner, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Caller is not the pending owner")]
+    fn accept_ownership_rejects_non_pending_owner() {
+        testing_env!(get_context_as(owner()));
+        let mut contract = TokenList::new(owner());
+        contract.propose_new_owner("new-owner.testnet".parse().unwrap());
+
+        testing_env!(get_context_as("stranger.testnet".parse().unwrap()));
+        contract.accept_ownership();
+    }
+
+    #[test]
+    #[should_panic(expected = "Token list is paused")]
+    fn add_token_rejects_when_paused() {
+        testing_env!(get_context_as(owner()));
+        let mut contract = TokenList::new(owner());
+        contract.pause();
+        contract.add_token("wrap.testnet".parse().unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "Caller is not the owner")]
+    fn pause_rejects_non_owner() {
+        testing_env!(get_context_as("stranger.testnet".parse().unwrap()));
+        let mut contract = TokenList::new(owner());
+        contract.pause();
+    }
+
+    #[test]
+    #[should_panic(expected = "Attached deposit does not cover storage staking cost")]
+    fn add_token_rejects_insufficient_deposit() {
+        testing_env!(get_context_as(owner()));
+        let mut contract = TokenList::new(owner());
+        contract.add_token("wrap.testnet".parse().unwrap());
+    }
+
+    #[test]
+    fn set_verification_gas_allows_owner() {
+        testing_env!(get_context_as(owner()));
+        let mut contract = TokenList::new(owner());
+        contract.set_verification_gas(Gas(20_000_000_000_000));
+        assert_eq!(contract.verification_gas, Gas(20_000_000_000_000));
+    }
+
+    #[test]
+    #[should_panic(expected = "Caller is not the owner")]
+    fn set_verification_gas_rejects_non_owner() {
+        testing_env!(get_context_as("stranger.testnet".parse().unwrap()));
+        let mut contract = TokenList::new(owner());
+        contract.set_verification_gas(Gas(20_000_000_000_000));
+    }
+
+    #[test]
+    fn remove_token_refunds_storage_to_depositor() {
+        testing_env!(get_context_as(owner()));
+        let mut contract = TokenList::new(owner());
+        let token: AccountId = "wrap.testnet".parse().unwrap();
+        let depositor: AccountId = "depositor.testnet".parse().unwrap();
+        let metadata = sample_metadata();
+        let cost = TokenList::storage_cost(TokenList::actual_entry_storage_bytes(&token, &metadata));
+        contract.tokens.insert(token.clone(), metadata);
+        contract.token_depositor.insert(token.clone(), depositor.clone());
+        contract.storage_balance.insert(depositor.clone(), cost);
+
+        assert!(contract.remove_token(token));
+        assert_eq!(contract.get_storage_balance(depositor).0, 0);
+    }
 }
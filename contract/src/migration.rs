@@ -0,0 +1,36 @@
+use crate::{TokenList, DEFAULT_VERIFICATION_GAS};
+use near_contract_standards::fungible_token::metadata::FungibleTokenMetadata;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::store::{LookupMap, UnorderedMap, UnorderedSet};
+use near_sdk::{AccountId, Balance};
+
+/// Pre-configurable-gas layout of `TokenList`, kept only so `migrate` can
+/// read state written by the previous contract version and reshape it
+/// into the current layout.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct OldTokenList {
+    pub owner: AccountId,
+    pub pending_owner: Option<AccountId>,
+    pub curators: UnorderedSet<AccountId>,
+    pub tokens: UnorderedMap<AccountId, FungibleTokenMetadata>,
+    pub paused: bool,
+    pub token_depositor: LookupMap<AccountId, AccountId>,
+    pub storage_balance: LookupMap<AccountId, Balance>,
+}
+
+impl From<OldTokenList> for TokenList {
+    fn from(old: OldTokenList) -> Self {
+        TokenList {
+            owner: old.owner,
+            pending_owner: old.pending_owner,
+            curators: old.curators,
+            tokens: old.tokens,
+            paused: old.paused,
+            token_depositor: old.token_depositor,
+            storage_balance: old.storage_balance,
+            // Contracts migrating from before verification gas was
+            // configurable start out on `DEFAULT_VERIFICATION_GAS`.
+            verification_gas: DEFAULT_VERIFICATION_GAS,
+        }
+    }
+}